@@ -36,6 +36,26 @@
 //! ulps_eq!(1.0, 1.0, max_ulps = 4);
 //! ulps_eq!(1.0, 1.0, epsilon = f64::EPSILON, max_ulps = 4);
 //! ulps_eq!(1.0, 1.0, max_ulps = 4, epsilon = f64::EPSILON);
+//!
+//! approx_eq!(1.0, 1.0);
+//! approx_eq!(1.0, 1.0, abs = f64::EPSILON);
+//! approx_eq!(1.0, 1.0, rel = 1.0);
+//! approx_eq!(1.0, 1.0, ulps = 4);
+//! approx_eq!(1.0, 1.0, abs = f64::EPSILON, rel = 1.0, ulps = 4);
+//! # }
+//! ```
+//!
+//! `ApproxEq` is also implemented element-wise for arrays, tuples, and `Option`, so the same
+//! macros work on compound values directly:
+//!
+//! ```rust
+//! #[macro_use]
+//! extern crate approx;
+//!
+//! # fn main() {
+//! relative_eq!([1.0, 2.0, 3.0], [1.0, 2.0, 3.0]);
+//! relative_eq!((1.0, 2.0), (1.0, 2.0));
+//! relative_eq!(Some(1.0), Some(1.0));
 //! # }
 //! ```
 //!
@@ -132,6 +152,30 @@ use core::num::Float;
 
 mod macros;
 
+// Helpers for the macros in the `macros` module. Calling `T::default_epsilon()` directly at a
+// macro call site leaves `T` to be inferred purely from the surrounding expression, which fails
+// with "cannot call associated function on trait without specifying the corresponding `impl`
+// type" whenever there is more than one `ApproxEq` impl in scope (as there is here, for `f32`
+// and `f64`). Routing the call through a function that also takes a `&T` pins `T` down from the
+// already-typed `lhs`/`rhs` argument instead.
+#[doc(hidden)]
+#[inline]
+pub fn __default_epsilon<T: ApproxEq>(_: &T) -> T::Epsilon {
+    T::default_epsilon()
+}
+
+#[doc(hidden)]
+#[inline]
+pub fn __default_max_relative<T: ApproxEq>(_: &T) -> T::Epsilon {
+    T::default_max_relative()
+}
+
+#[doc(hidden)]
+#[inline]
+pub fn __default_max_ulps<T: ApproxEq>(_: &T) -> u32 {
+    T::default_max_ulps()
+}
+
 /// Equality comparisons based on floating point tolerances.
 pub trait ApproxEq: Sized {
     /// Used for specifying relative comparisons.
@@ -169,16 +213,108 @@ pub trait ApproxEq: Sized {
     }
 
     /// A test for equality that uses units in the last place (ULP) if the values are far apart.
+    ///
+    /// The distance is computed on a key that is monotonic across the whole float line, so
+    /// values on opposite sides of zero, and values with extreme magnitudes, compare
+    /// correctly instead of appearing infinitely far apart or overflowing:
+    ///
+    /// ```rust
+    /// #[macro_use]
+    /// extern crate approx;
+    /// use std::f32;
+    ///
+    /// # fn main() {
+    /// // `+0.0` and `-0.0` are zero ULPs apart. Their absolute difference is also zero, so
+    /// // even with `epsilon = 0.0` this passes through the `abs_diff <= epsilon` fast path.
+    /// assert_ulps_eq!(0.0_f32, -0.0_f32, epsilon = 0.0);
+    ///
+    /// // The smallest positive and negative subnormals are only 2 ULPs apart.
+    /// assert_ulps_eq!(f32::from_bits(1), -f32::from_bits(1), epsilon = 0.0, max_ulps = 3);
+    ///
+    /// // Extreme magnitudes no longer overflow the distance calculation.
+    /// assert_ulps_eq!(f32::MAX, f32::MAX);
+    /// assert_ulps_ne!(f32::MAX, f32::MIN, max_ulps = 4);
+    ///
+    /// // NaN is never approximately equal to anything, including itself.
+    /// assert_ulps_ne!(f32::NAN, f32::NAN);
+    /// # }
+    /// ```
     fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool;
 
     /// The inverse of `ApproxEq::ulps_eq`.
     fn ulps_ne(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
         !Self::ulps_eq(self, other, epsilon, max_ulps)
     }
+
+    /// Returns a breakdown of how far apart `self` and `other` actually measured, if this
+    /// type is able to report one.
+    ///
+    /// This is used by the `assert_relative_{eq,ne}!` and `assert_ulps_{eq,ne}!` macros to
+    /// print a diagnostic alongside the usual `left`/`right` values on failure. The default
+    /// implementation has no further detail to report; the floating point impls override it.
+    fn approx_diff(&self, _other: &Self) -> Option<ApproxDiff> {
+        None
+    }
+}
+
+/// The measured absolute difference, relative difference, and ULPs distance between two
+/// values that failed an approximate equality assertion.
+///
+/// See [`ApproxEq::approx_diff`](trait.ApproxEq.html#method.approx_diff).
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ApproxDiff {
+    /// The absolute difference between the two values.
+    pub abs_diff: f64,
+    /// The absolute difference divided by the larger of the two values' magnitudes.
+    pub relative_diff: f64,
+    /// The distance between the two values, in units in the last place.
+    pub ulps: u64,
+}
+
+/// Provides the distance, in units in the last place (ULPs), between two floating point
+/// values, along with helpers for stepping to the neighbouring representable value.
+///
+/// This is not normally used directly, rather via the `ulps_eq!`/`assert_ulps_eq!` macros,
+/// which use it internally, or via the [`Ulps`](struct.Ulps.html) builder. It is exposed
+/// separately so that callers who want to pick a `max_ulps` threshold empirically can see
+/// the actual measured distance.
+///
+/// # Example
+///
+/// ```rust
+/// use approx::UlpsDistance;
+///
+/// // `+0.0` and `-0.0` share a distance of zero...
+/// assert_eq!(UlpsDistance::ulps_distance(&0.0_f64, &-0.0_f64), 0);
+/// // ...and stepping away from either zero skips the other one and lands on the smallest
+/// // subnormal.
+/// assert_eq!(UlpsDistance::next_up(&0.0_f64), f64::from_bits(1));
+/// assert_eq!(UlpsDistance::next_down(&-0.0_f64), -f64::from_bits(1));
+///
+/// // Infinities have no representable neighbour beyond themselves.
+/// assert_eq!(UlpsDistance::next_up(&f64::INFINITY), f64::INFINITY);
+/// assert_eq!(UlpsDistance::next_down(&f64::NEG_INFINITY), f64::NEG_INFINITY);
+/// ```
+pub trait UlpsDistance: Sized {
+    /// The unsigned integer type used to express a distance in ULPs.
+    type Ulps;
+
+    /// Returns how many `next_up` steps separate the lesser of `self` and `other` from the
+    /// greater, with `+0.0` and `-0.0` counted as the same value.
+    ///
+    /// This uses the same monotonic bit-remapping as `ApproxEq::ulps_eq`, so the result is
+    /// correct even when `self` and `other` are on opposite sides of zero.
+    fn ulps_distance(&self, other: &Self) -> Self::Ulps;
+
+    /// Returns the next representable value above `self`.
+    fn next_up(&self) -> Self;
+
+    /// Returns the next representable value below `self`.
+    fn next_down(&self) -> Self;
 }
 
 macro_rules! impl_float_approx_eq {
-    ($T:ident, $U:ident) => {
+    ($T:ident, $U:ident, $V:ident) => {
         impl ApproxEq for $T {
             type Epsilon = $T;
 
@@ -227,6 +363,11 @@ macro_rules! impl_float_approx_eq {
                 // Implementation based on: [Comparing Floating Point Numbers, 2012 Edition]
                 // (https://randomascii.wordpress.com/2012/02/25/comparing-floating-point-numbers-2012-edition/)
 
+                // NaN is never approximately equal to anything, including itself.
+                if self.is_nan() || other.is_nan() {
+                    return false;
+                }
+
                 let abs_diff = $T::abs(self - other);
 
                 // For when the numbers are really close together
@@ -234,23 +375,99 @@ macro_rules! impl_float_approx_eq {
                     return true;
                 }
 
-                // Trivial negative sign check
-                if self.signum() != other.signum() {
-                    return false;
+                // ULPs difference comparison, via the monotonic bit-remapping in
+                // `UlpsDistance::ulps_distance`, which stays correct across sign boundaries.
+                (UlpsDistance::ulps_distance(self, other) as u64) < max_ulps as u64
+            }
+
+            #[inline]
+            fn approx_diff(&self, other: &$T) -> Option<ApproxDiff> {
+                let abs_diff = $T::abs(self - other) as f64;
+
+                let abs_self = $T::abs(*self) as f64;
+                let abs_other = $T::abs(*other) as f64;
+                let largest = if abs_other > abs_self { abs_other } else { abs_self };
+                let relative_diff = if largest == 0.0 { 0.0 } else { abs_diff / largest };
+
+                let ulps = UlpsDistance::ulps_distance(self, other) as u64;
+
+                Some(ApproxDiff {
+                    abs_diff: abs_diff,
+                    relative_diff: relative_diff,
+                    ulps: ulps,
+                })
+            }
+        }
+
+        impl UlpsDistance for $T {
+            type Ulps = $V;
+
+            #[inline]
+            fn ulps_distance(&self, other: &$T) -> $V {
+                // The raw bit patterns of the two floats are reinterpreted as signed
+                // integers and remapped to a key that is monotonic across the *whole*
+                // float line: negative values (sign bit set) are folded via
+                // `$U::min_value() - bits`, so `+0.0` and `-0.0` end up on the same
+                // key instead of `0x8000_0000` apart, and the ordering no longer
+                // breaks down as values cross zero. The keys are widened to `i128`
+                // before subtracting, so the distance cannot overflow even for
+                // large-magnitude or denormal inputs.
+                #[inline]
+                fn ulps_key(bits: $U) -> $U {
+                    if bits < 0 {
+                        $U::min_value().wrapping_sub(bits)
+                    } else {
+                        bits
+                    }
                 }
 
-                // ULPS difference comparison
-                let int_self: $U = unsafe { std::mem::transmute(*self) };
-                let int_other: $U = unsafe { std::mem::transmute(*other) };
+                let self_key = ulps_key($T::to_bits(*self).cast_signed());
+                let other_key = ulps_key($T::to_bits(*other).cast_signed());
+
+                (self_key as i128 - other_key as i128).unsigned_abs() as $V
+            }
+
+            #[inline]
+            fn next_up(&self) -> $T {
+                let bits = $T::to_bits(*self).cast_signed();
+
+                let next_bits = if *self == std::$T::INFINITY || self.is_nan() {
+                    bits
+                } else if bits == $U::min_value() {
+                    // The least value greater than -0.0 is the smallest positive subnormal.
+                    1
+                } else if bits >= 0 {
+                    bits + 1
+                } else {
+                    bits - 1
+                };
+
+                $T::from_bits(next_bits.cast_unsigned())
+            }
 
-                $U::abs(int_self - int_other) < max_ulps as $U
+            #[inline]
+            fn next_down(&self) -> $T {
+                let bits = $T::to_bits(*self).cast_signed();
+
+                let next_bits = if *self == std::$T::NEG_INFINITY || self.is_nan() {
+                    bits
+                } else if bits == 0 {
+                    // The greatest value less than +0.0 is the smallest negative subnormal.
+                    $U::min_value() + 1
+                } else if bits > 0 {
+                    bits - 1
+                } else {
+                    bits + 1
+                };
+
+                $T::from_bits(next_bits.cast_unsigned())
             }
         }
     }
 }
 
-impl_float_approx_eq!(f32, i32);
-impl_float_approx_eq!(f64, i64);
+impl_float_approx_eq!(f32, i32, u32);
+impl_float_approx_eq!(f64, i64, u64);
 
 
 impl<'a, T: ApproxEq> ApproxEq for &'a T {
@@ -315,6 +532,240 @@ impl<'a, T: ApproxEq> ApproxEq for &'a mut T {
     }
 }
 
+/// Compares slices element-wise, failing immediately if the lengths differ.
+///
+/// This is implemented for the slice reference `&[T]` itself, and the `relative_eq!`/
+/// `ulps_eq!` macros always take a reference of whatever expression they are given, so
+/// they end up needing `&&[T]` here. That means comparing two `Vec<T>`s needs an explicit
+/// `.as_slice()` (or any other conversion to `&[T]`) rather than comparing the `Vec`s or a
+/// `v[..]` slicing expression directly, since `Vec<T>` and the unsized `[T]` do not
+/// implement `ApproxEq` themselves:
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approx;
+///
+/// # fn main() {
+/// let a: Vec<f64> = vec![1.0, 2.0, 3.0];
+/// let b: Vec<f64> = vec![1.0, 2.0, 3.0];
+/// assert_relative_eq!(a.as_slice(), b.as_slice());
+///
+/// let c: Vec<f64> = vec![1.0, 2.0];
+/// assert!(!relative_eq!(a.as_slice(), c.as_slice()));
+/// # }
+/// ```
+impl<'a, T: ApproxEq> ApproxEq for &'a [T]
+    where T::Epsilon: Copy
+{
+    type Epsilon = T::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &&'a [T], epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.len() == other.len() &&
+        self.iter().zip(other.iter()).all(|(x, y)| T::relative_eq(x, y, epsilon, max_relative))
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &&'a [T], epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.len() == other.len() &&
+        self.iter().zip(other.iter()).all(|(x, y)| T::ulps_eq(x, y, epsilon, max_ulps))
+    }
+}
+
+/// `None` is only approximately equal to `None`; a `Some`/`None` pairing, in either order,
+/// always compares unequal.
+///
+/// # Example
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate approx;
+///
+/// # fn main() {
+/// let none: Option<f64> = None;
+/// assert_relative_eq!(none, None);
+/// assert_relative_eq!(Some(1.0), Some(1.0));
+/// assert!(!relative_eq!(Some(1.0), None::<f64>));
+/// assert!(!relative_eq!(none, Some(1.0)));
+/// # }
+/// ```
+impl<T: ApproxEq> ApproxEq for Option<T>
+    where T::Epsilon: Copy
+{
+    type Epsilon = T::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    #[inline]
+    fn relative_eq(&self, other: &Option<T>, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        match (self, other) {
+            (&Some(ref x), &Some(ref y)) => T::relative_eq(x, y, epsilon, max_relative),
+            (&None, &None) => true,
+            _ => false,
+        }
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Option<T>, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        match (self, other) {
+            (&Some(ref x), &Some(ref y)) => T::ulps_eq(x, y, epsilon, max_ulps),
+            (&None, &None) => true,
+            _ => false,
+        }
+    }
+}
+
+macro_rules! impl_approx_eq_for_array {
+    ($N:expr) => {
+        impl<T: ApproxEq> ApproxEq for [T; $N]
+            where T::Epsilon: Copy
+        {
+            type Epsilon = T::Epsilon;
+
+            #[inline]
+            fn default_epsilon() -> Self::Epsilon {
+                T::default_epsilon()
+            }
+
+            #[inline]
+            fn default_max_relative() -> Self::Epsilon {
+                T::default_max_relative()
+            }
+
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                T::default_max_ulps()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &[T; $N], epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+                self.iter().zip(other.iter()).all(|(x, y)| T::relative_eq(x, y, epsilon, max_relative))
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &[T; $N], epsilon: T::Epsilon, max_ulps: u32) -> bool {
+                self.iter().zip(other.iter()).all(|(x, y)| T::ulps_eq(x, y, epsilon, max_ulps))
+            }
+        }
+    }
+}
+
+impl_approx_eq_for_array!(0);
+impl_approx_eq_for_array!(1);
+impl_approx_eq_for_array!(2);
+impl_approx_eq_for_array!(3);
+impl_approx_eq_for_array!(4);
+impl_approx_eq_for_array!(5);
+impl_approx_eq_for_array!(6);
+impl_approx_eq_for_array!(7);
+impl_approx_eq_for_array!(8);
+impl_approx_eq_for_array!(9);
+impl_approx_eq_for_array!(10);
+impl_approx_eq_for_array!(11);
+impl_approx_eq_for_array!(12);
+impl_approx_eq_for_array!(13);
+impl_approx_eq_for_array!(14);
+impl_approx_eq_for_array!(15);
+impl_approx_eq_for_array!(16);
+impl_approx_eq_for_array!(17);
+impl_approx_eq_for_array!(18);
+impl_approx_eq_for_array!(19);
+impl_approx_eq_for_array!(20);
+impl_approx_eq_for_array!(21);
+impl_approx_eq_for_array!(22);
+impl_approx_eq_for_array!(23);
+impl_approx_eq_for_array!(24);
+impl_approx_eq_for_array!(25);
+impl_approx_eq_for_array!(26);
+impl_approx_eq_for_array!(27);
+impl_approx_eq_for_array!(28);
+impl_approx_eq_for_array!(29);
+impl_approx_eq_for_array!(30);
+impl_approx_eq_for_array!(31);
+impl_approx_eq_for_array!(32);
+
+macro_rules! impl_approx_eq_for_tuple {
+    ($head:ident : $head_idx:tt $(, $T:ident : $idx:tt)*) => {
+        impl<$head: ApproxEq $(, $T: ApproxEq)*> ApproxEq for ($head, $($T,)*)
+            where $head::Epsilon: Copy $(, $T::Epsilon: Copy)*
+        {
+            type Epsilon = ($head::Epsilon, $($T::Epsilon,)*);
+
+            #[inline]
+            fn default_epsilon() -> Self::Epsilon {
+                ($head::default_epsilon(), $($T::default_epsilon(),)*)
+            }
+
+            #[inline]
+            fn default_max_relative() -> Self::Epsilon {
+                ($head::default_max_relative(), $($T::default_max_relative(),)*)
+            }
+
+            #[inline]
+            fn default_max_ulps() -> u32 {
+                // All of the default `max_ulps` are equal in practice, so the first
+                // element's is as good as any.
+                $head::default_max_ulps()
+            }
+
+            #[inline]
+            fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool {
+                $head::relative_eq(&self.$head_idx, &other.$head_idx, epsilon.$head_idx, max_relative.$head_idx)
+                $(&& $T::relative_eq(&self.$idx, &other.$idx, epsilon.$idx, max_relative.$idx))*
+            }
+
+            #[inline]
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                $head::ulps_eq(&self.$head_idx, &other.$head_idx, epsilon.$head_idx, max_ulps)
+                $(&& $T::ulps_eq(&self.$idx, &other.$idx, epsilon.$idx, max_ulps))*
+            }
+        }
+    }
+}
+
+impl_approx_eq_for_tuple!(A:0);
+impl_approx_eq_for_tuple!(A:0, B:1);
+impl_approx_eq_for_tuple!(A:0, B:1, C:2);
+impl_approx_eq_for_tuple!(A:0, B:1, C:2, D:3);
+impl_approx_eq_for_tuple!(A:0, B:1, C:2, D:3, E:4);
+impl_approx_eq_for_tuple!(A:0, B:1, C:2, D:3, E:4, F:5);
+impl_approx_eq_for_tuple!(A:0, B:1, C:2, D:3, E:4, F:5, G:6);
+impl_approx_eq_for_tuple!(A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7);
+impl_approx_eq_for_tuple!(A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7, I:8);
+impl_approx_eq_for_tuple!(A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7, I:8, J:9);
+impl_approx_eq_for_tuple!(A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7, I:8, J:9, K:10);
+impl_approx_eq_for_tuple!(A:0, B:1, C:2, D:3, E:4, F:5, G:6, H:7, I:8, J:9, K:10, L:11);
+
 /// The requisite parameters for testing for approximate equality using a
 /// relative based comparison.
 ///
@@ -456,3 +907,96 @@ impl<T> Ulps<T>
         T::ulps_ne(lhs, rhs, self.epsilon, self.max_ulps)
     }
 }
+
+/// The requisite parameters for testing for approximate equality using a combined absolute,
+/// relative, and ULPs based comparison, succeeding if *any* one of the three checks passes.
+///
+/// This is not normally used directly, rather via the `assert_approx_{eq|ne}!` and
+/// `approx_{eq|ne}!` macros, which let callers mix and match `abs =`, `rel =`, and `ulps =`
+/// keyword arguments instead of committing to a single comparison mode up front.
+///
+/// # Example
+///
+/// ```rust
+/// use std::f64;
+/// use approx::Margin;
+///
+/// Margin::default().eq(&1.0, &1.0);
+/// Margin::default().abs(f64::EPSILON).eq(&1.0, &1.0);
+/// Margin::default().relative(1.0).eq(&1.0, &1.0);
+/// Margin::default().ulps(4).eq(&1.0, &1.0);
+/// Margin::default().abs(f64::EPSILON).relative(1.0).ulps(4).eq(&1.0, &1.0);
+/// ```
+pub struct Margin<T: ApproxEq> {
+    /// The absolute tolerance to use when testing values that are close together.
+    pub abs: T::Epsilon,
+    /// The relative tolerance for testing values that are far-apart.
+    pub relative: T::Epsilon,
+    /// The ULPs to tolerate when testing values that are far-apart.
+    pub ulps: u32,
+}
+
+impl<T> Default for Margin<T>
+    where T: ApproxEq
+{
+    #[inline]
+    fn default() -> Margin<T> {
+        Margin {
+            abs: T::default_epsilon(),
+            relative: T::default_max_relative(),
+            ulps: T::default_max_ulps(),
+        }
+    }
+}
+
+impl<T> Margin<T>
+    where T: ApproxEq
+{
+    /// Replace the absolute tolerance with the one specified.
+    #[inline]
+    pub fn abs(self, abs: T::Epsilon) -> Margin<T> {
+        Margin {
+            abs: abs,
+            ..self
+        }
+    }
+
+    /// Replace the relative tolerance with the one specified.
+    #[inline]
+    pub fn relative(self, relative: T::Epsilon) -> Margin<T> {
+        Margin {
+            relative: relative,
+            ..self
+        }
+    }
+
+    /// Replace the ULPs tolerance with the one specified.
+    #[inline]
+    pub fn ulps(self, ulps: u32) -> Margin<T> {
+        Margin {
+            ulps: ulps,
+            ..self
+        }
+    }
+
+    /// Perform the equality comparison, succeeding if `lhs` and `rhs` are within the absolute
+    /// tolerance, *or* within the relative tolerance, *or* within the ULPs tolerance.
+    #[inline]
+    pub fn eq(self, lhs: &T, rhs: &T) -> bool
+        where T::Epsilon: Copy
+    {
+        // `relative_eq` already succeeds when `abs_diff <= self.abs` (the absolute check) or
+        // when the relative check passes, and likewise `ulps_eq` already tries the same
+        // absolute check before the ULPs check, so ORing the two together gives
+        // exactly "abs or relative or ulps" without re-deriving any of the comparisons.
+        T::relative_eq(lhs, rhs, self.abs, self.relative) || T::ulps_eq(lhs, rhs, self.abs, self.ulps)
+    }
+
+    /// The inverse of `Margin::eq`.
+    #[inline]
+    pub fn ne(self, lhs: &T, rhs: &T) -> bool
+        where T::Epsilon: Copy
+    {
+        !self.eq(lhs, rhs)
+    }
+}
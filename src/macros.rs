@@ -0,0 +1,416 @@
+// Copyright 2015 Brendan Zabarauskas
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Equality that is defined using the absolute difference of two numbers, with fallback to a
+/// relative difference when the numbers are far apart.
+#[macro_export]
+macro_rules! relative_eq {
+    ($lhs:expr, $rhs:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        $crate::ApproxEq::relative_eq(lhs, rhs,
+                                       $crate::__default_epsilon(lhs),
+                                       $crate::__default_max_relative(lhs))
+    }};
+    ($lhs:expr, $rhs:expr, epsilon = $epsilon:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        $crate::ApproxEq::relative_eq(lhs, rhs,
+                                       $epsilon,
+                                       $crate::__default_max_relative(lhs))
+    }};
+    ($lhs:expr, $rhs:expr, max_relative = $max_relative:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        $crate::ApproxEq::relative_eq(lhs, rhs,
+                                       $crate::__default_epsilon(lhs),
+                                       $max_relative)
+    }};
+    ($lhs:expr, $rhs:expr, epsilon = $epsilon:expr, max_relative = $max_relative:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        $crate::ApproxEq::relative_eq(lhs, rhs, $epsilon, $max_relative)
+    }};
+    ($lhs:expr, $rhs:expr, max_relative = $max_relative:expr, epsilon = $epsilon:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        $crate::ApproxEq::relative_eq(lhs, rhs, $epsilon, $max_relative)
+    }};
+}
+
+/// The inverse of `relative_eq!`.
+#[macro_export]
+macro_rules! relative_ne {
+    ($lhs:expr, $rhs:expr) => {
+        !$crate::relative_eq!($lhs, $rhs)
+    };
+    ($lhs:expr, $rhs:expr, epsilon = $epsilon:expr) => {
+        !$crate::relative_eq!($lhs, $rhs, epsilon = $epsilon)
+    };
+    ($lhs:expr, $rhs:expr, max_relative = $max_relative:expr) => {
+        !$crate::relative_eq!($lhs, $rhs, max_relative = $max_relative)
+    };
+    ($lhs:expr, $rhs:expr, epsilon = $epsilon:expr, max_relative = $max_relative:expr) => {
+        !$crate::relative_eq!($lhs, $rhs, epsilon = $epsilon, max_relative = $max_relative)
+    };
+    ($lhs:expr, $rhs:expr, max_relative = $max_relative:expr, epsilon = $epsilon:expr) => {
+        !$crate::relative_eq!($lhs, $rhs, epsilon = $epsilon, max_relative = $max_relative)
+    };
+}
+
+/// Equality that is defined using units in the last place (ULPs), with fallback to an absolute
+/// difference when the numbers are close together.
+#[macro_export]
+macro_rules! ulps_eq {
+    ($lhs:expr, $rhs:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        $crate::ApproxEq::ulps_eq(lhs, rhs,
+                                   $crate::__default_epsilon(lhs),
+                                   $crate::__default_max_ulps(lhs))
+    }};
+    ($lhs:expr, $rhs:expr, epsilon = $epsilon:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        $crate::ApproxEq::ulps_eq(lhs, rhs,
+                                   $epsilon,
+                                   $crate::__default_max_ulps(lhs))
+    }};
+    ($lhs:expr, $rhs:expr, max_ulps = $max_ulps:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        $crate::ApproxEq::ulps_eq(lhs, rhs,
+                                   $crate::__default_epsilon(lhs),
+                                   $max_ulps)
+    }};
+    ($lhs:expr, $rhs:expr, epsilon = $epsilon:expr, max_ulps = $max_ulps:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        $crate::ApproxEq::ulps_eq(lhs, rhs, $epsilon, $max_ulps)
+    }};
+    ($lhs:expr, $rhs:expr, max_ulps = $max_ulps:expr, epsilon = $epsilon:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        $crate::ApproxEq::ulps_eq(lhs, rhs, $epsilon, $max_ulps)
+    }};
+}
+
+/// The inverse of `ulps_eq!`.
+#[macro_export]
+macro_rules! ulps_ne {
+    ($lhs:expr, $rhs:expr) => {
+        !$crate::ulps_eq!($lhs, $rhs)
+    };
+    ($lhs:expr, $rhs:expr, epsilon = $epsilon:expr) => {
+        !$crate::ulps_eq!($lhs, $rhs, epsilon = $epsilon)
+    };
+    ($lhs:expr, $rhs:expr, max_ulps = $max_ulps:expr) => {
+        !$crate::ulps_eq!($lhs, $rhs, max_ulps = $max_ulps)
+    };
+    ($lhs:expr, $rhs:expr, epsilon = $epsilon:expr, max_ulps = $max_ulps:expr) => {
+        !$crate::ulps_eq!($lhs, $rhs, epsilon = $epsilon, max_ulps = $max_ulps)
+    };
+    ($lhs:expr, $rhs:expr, max_ulps = $max_ulps:expr, epsilon = $epsilon:expr) => {
+        !$crate::ulps_eq!($lhs, $rhs, epsilon = $epsilon, max_ulps = $max_ulps)
+    };
+}
+
+/// Builds the panic used by the `assert_*!` macros below.
+///
+/// If `$lhs`/`$rhs` are able to report an `ApproxDiff` (currently just the floating point
+/// types), the measured absolute difference, relative difference, and ULPs distance are
+/// appended to the usual `left`/`right` panic message.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __approx_assert_fail {
+    ($desc:expr, $lhs:expr, $rhs:expr) => {
+        match $crate::ApproxEq::approx_diff($lhs, $rhs) {
+            Some(diff) => {
+                panic!("assertion failed: `{}`\n\
+                        (left: `{:?}`, right: `{:?}`)\n\
+                        abs diff = {:e}, rel diff = {:e}, ulps = {}",
+                       $desc, $lhs, $rhs, diff.abs_diff, diff.relative_diff, diff.ulps)
+            }
+            None => {
+                panic!("assertion failed: `{}`\n\
+                        (left: `{:?}`, right: `{:?}`)",
+                       $desc, $lhs, $rhs)
+            }
+        }
+    };
+}
+
+/// An assertion that delegates to `relative_eq!`, and panics with a helpful error on failure.
+#[macro_export]
+macro_rules! assert_relative_eq {
+    ($lhs:expr, $rhs:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        if !$crate::relative_eq!(*lhs, *rhs) {
+            $crate::__approx_assert_fail!(
+                concat!("relative_eq!(", stringify!($lhs), ", ", stringify!($rhs), ")"),
+                lhs, rhs);
+        }
+    }};
+    ($lhs:expr, $rhs:expr, epsilon = $epsilon:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        if !$crate::relative_eq!(*lhs, *rhs, epsilon = $epsilon) {
+            $crate::__approx_assert_fail!(
+                concat!("relative_eq!(", stringify!($lhs), ", ", stringify!($rhs),
+                        ", epsilon = ", stringify!($epsilon), ")"),
+                lhs, rhs);
+        }
+    }};
+    ($lhs:expr, $rhs:expr, max_relative = $max_relative:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        if !$crate::relative_eq!(*lhs, *rhs, max_relative = $max_relative) {
+            $crate::__approx_assert_fail!(
+                concat!("relative_eq!(", stringify!($lhs), ", ", stringify!($rhs),
+                        ", max_relative = ", stringify!($max_relative), ")"),
+                lhs, rhs);
+        }
+    }};
+    ($lhs:expr, $rhs:expr, epsilon = $epsilon:expr, max_relative = $max_relative:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        if !$crate::relative_eq!(*lhs, *rhs, epsilon = $epsilon, max_relative = $max_relative) {
+            $crate::__approx_assert_fail!(
+                concat!("relative_eq!(", stringify!($lhs), ", ", stringify!($rhs),
+                        ", epsilon = ", stringify!($epsilon),
+                        ", max_relative = ", stringify!($max_relative), ")"),
+                lhs, rhs);
+        }
+    }};
+    ($lhs:expr, $rhs:expr, max_relative = $max_relative:expr, epsilon = $epsilon:expr) => {
+        $crate::assert_relative_eq!($lhs, $rhs, epsilon = $epsilon, max_relative = $max_relative)
+    };
+}
+
+/// The inverse of `assert_relative_eq!`.
+#[macro_export]
+macro_rules! assert_relative_ne {
+    ($lhs:expr, $rhs:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        if $crate::relative_eq!(*lhs, *rhs) {
+            $crate::__approx_assert_fail!(
+                concat!("relative_ne!(", stringify!($lhs), ", ", stringify!($rhs), ")"),
+                lhs, rhs);
+        }
+    }};
+    ($lhs:expr, $rhs:expr, epsilon = $epsilon:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        if $crate::relative_eq!(*lhs, *rhs, epsilon = $epsilon) {
+            $crate::__approx_assert_fail!(
+                concat!("relative_ne!(", stringify!($lhs), ", ", stringify!($rhs),
+                        ", epsilon = ", stringify!($epsilon), ")"),
+                lhs, rhs);
+        }
+    }};
+    ($lhs:expr, $rhs:expr, max_relative = $max_relative:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        if $crate::relative_eq!(*lhs, *rhs, max_relative = $max_relative) {
+            $crate::__approx_assert_fail!(
+                concat!("relative_ne!(", stringify!($lhs), ", ", stringify!($rhs),
+                        ", max_relative = ", stringify!($max_relative), ")"),
+                lhs, rhs);
+        }
+    }};
+    ($lhs:expr, $rhs:expr, epsilon = $epsilon:expr, max_relative = $max_relative:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        if $crate::relative_eq!(*lhs, *rhs, epsilon = $epsilon, max_relative = $max_relative) {
+            $crate::__approx_assert_fail!(
+                concat!("relative_ne!(", stringify!($lhs), ", ", stringify!($rhs),
+                        ", epsilon = ", stringify!($epsilon),
+                        ", max_relative = ", stringify!($max_relative), ")"),
+                lhs, rhs);
+        }
+    }};
+    ($lhs:expr, $rhs:expr, max_relative = $max_relative:expr, epsilon = $epsilon:expr) => {
+        $crate::assert_relative_ne!($lhs, $rhs, epsilon = $epsilon, max_relative = $max_relative)
+    };
+}
+
+/// An assertion that delegates to `ulps_eq!`, and panics with a helpful error on failure.
+#[macro_export]
+macro_rules! assert_ulps_eq {
+    ($lhs:expr, $rhs:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        if !$crate::ulps_eq!(*lhs, *rhs) {
+            $crate::__approx_assert_fail!(
+                concat!("ulps_eq!(", stringify!($lhs), ", ", stringify!($rhs), ")"),
+                lhs, rhs);
+        }
+    }};
+    ($lhs:expr, $rhs:expr, epsilon = $epsilon:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        if !$crate::ulps_eq!(*lhs, *rhs, epsilon = $epsilon) {
+            $crate::__approx_assert_fail!(
+                concat!("ulps_eq!(", stringify!($lhs), ", ", stringify!($rhs),
+                        ", epsilon = ", stringify!($epsilon), ")"),
+                lhs, rhs);
+        }
+    }};
+    ($lhs:expr, $rhs:expr, max_ulps = $max_ulps:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        if !$crate::ulps_eq!(*lhs, *rhs, max_ulps = $max_ulps) {
+            $crate::__approx_assert_fail!(
+                concat!("ulps_eq!(", stringify!($lhs), ", ", stringify!($rhs),
+                        ", max_ulps = ", stringify!($max_ulps), ")"),
+                lhs, rhs);
+        }
+    }};
+    ($lhs:expr, $rhs:expr, epsilon = $epsilon:expr, max_ulps = $max_ulps:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        if !$crate::ulps_eq!(*lhs, *rhs, epsilon = $epsilon, max_ulps = $max_ulps) {
+            $crate::__approx_assert_fail!(
+                concat!("ulps_eq!(", stringify!($lhs), ", ", stringify!($rhs),
+                        ", epsilon = ", stringify!($epsilon),
+                        ", max_ulps = ", stringify!($max_ulps), ")"),
+                lhs, rhs);
+        }
+    }};
+    ($lhs:expr, $rhs:expr, max_ulps = $max_ulps:expr, epsilon = $epsilon:expr) => {
+        $crate::assert_ulps_eq!($lhs, $rhs, epsilon = $epsilon, max_ulps = $max_ulps)
+    };
+}
+
+/// The inverse of `assert_ulps_eq!`.
+#[macro_export]
+macro_rules! assert_ulps_ne {
+    ($lhs:expr, $rhs:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        if $crate::ulps_eq!(*lhs, *rhs) {
+            $crate::__approx_assert_fail!(
+                concat!("ulps_ne!(", stringify!($lhs), ", ", stringify!($rhs), ")"),
+                lhs, rhs);
+        }
+    }};
+    ($lhs:expr, $rhs:expr, epsilon = $epsilon:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        if $crate::ulps_eq!(*lhs, *rhs, epsilon = $epsilon) {
+            $crate::__approx_assert_fail!(
+                concat!("ulps_ne!(", stringify!($lhs), ", ", stringify!($rhs),
+                        ", epsilon = ", stringify!($epsilon), ")"),
+                lhs, rhs);
+        }
+    }};
+    ($lhs:expr, $rhs:expr, max_ulps = $max_ulps:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        if $crate::ulps_eq!(*lhs, *rhs, max_ulps = $max_ulps) {
+            $crate::__approx_assert_fail!(
+                concat!("ulps_ne!(", stringify!($lhs), ", ", stringify!($rhs),
+                        ", max_ulps = ", stringify!($max_ulps), ")"),
+                lhs, rhs);
+        }
+    }};
+    ($lhs:expr, $rhs:expr, epsilon = $epsilon:expr, max_ulps = $max_ulps:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        if $crate::ulps_eq!(*lhs, *rhs, epsilon = $epsilon, max_ulps = $max_ulps) {
+            $crate::__approx_assert_fail!(
+                concat!("ulps_ne!(", stringify!($lhs), ", ", stringify!($rhs),
+                        ", epsilon = ", stringify!($epsilon),
+                        ", max_ulps = ", stringify!($max_ulps), ")"),
+                lhs, rhs);
+        }
+    }};
+    ($lhs:expr, $rhs:expr, max_ulps = $max_ulps:expr, epsilon = $epsilon:expr) => {
+        $crate::assert_ulps_ne!($lhs, $rhs, epsilon = $epsilon, max_ulps = $max_ulps)
+    };
+}
+
+/// Applies a sequence of `abs = ...`, `rel = ...`, `ulps = ...` keyword arguments (in any
+/// combination, in any order) to a `Margin`, one at a time.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __approx_margin {
+    ($margin:expr) => {
+        $margin
+    };
+    ($margin:expr, abs = $val:expr) => {
+        $margin.abs($val)
+    };
+    ($margin:expr, abs = $val:expr, $($rest:tt)+) => {
+        $crate::__approx_margin!($margin.abs($val), $($rest)+)
+    };
+    ($margin:expr, rel = $val:expr) => {
+        $margin.relative($val)
+    };
+    ($margin:expr, rel = $val:expr, $($rest:tt)+) => {
+        $crate::__approx_margin!($margin.relative($val), $($rest)+)
+    };
+    ($margin:expr, ulps = $val:expr) => {
+        $margin.ulps($val)
+    };
+    ($margin:expr, ulps = $val:expr, $($rest:tt)+) => {
+        $crate::__approx_margin!($margin.ulps($val), $($rest)+)
+    };
+}
+
+/// Equality that succeeds if `lhs` and `rhs` are within an absolute tolerance, a relative
+/// tolerance, or a ULPs tolerance of one another — see `Margin`. Accepts `abs =`, `rel =`, and
+/// `ulps =` keyword arguments in any combination and any order.
+#[macro_export]
+macro_rules! approx_eq {
+    ($lhs:expr, $rhs:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        $crate::Margin::default().eq(lhs, rhs)
+    }};
+    ($lhs:expr, $rhs:expr, $($rest:tt)+) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        $crate::__approx_margin!($crate::Margin::default(), $($rest)+).eq(lhs, rhs)
+    }};
+}
+
+/// The inverse of `approx_eq!`.
+#[macro_export]
+macro_rules! approx_ne {
+    ($lhs:expr, $rhs:expr) => {
+        !$crate::approx_eq!($lhs, $rhs)
+    };
+    ($lhs:expr, $rhs:expr, $($rest:tt)+) => {
+        !$crate::approx_eq!($lhs, $rhs, $($rest)+)
+    };
+}
+
+/// An assertion that delegates to `approx_eq!`, and panics with a helpful error on failure.
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($lhs:expr, $rhs:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        if !$crate::Margin::default().eq(lhs, rhs) {
+            $crate::__approx_assert_fail!(
+                concat!("approx_eq!(", stringify!($lhs), ", ", stringify!($rhs), ")"),
+                lhs, rhs);
+        }
+    }};
+    ($lhs:expr, $rhs:expr, $($rest:tt)+) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        if !$crate::__approx_margin!($crate::Margin::default(), $($rest)+).eq(lhs, rhs) {
+            $crate::__approx_assert_fail!(
+                concat!("approx_eq!(", stringify!($lhs), ", ", stringify!($rhs),
+                        ", ", stringify!($($rest)+), ")"),
+                lhs, rhs);
+        }
+    }};
+}
+
+/// The inverse of `assert_approx_eq!`.
+#[macro_export]
+macro_rules! assert_approx_ne {
+    ($lhs:expr, $rhs:expr) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        if $crate::Margin::default().eq(lhs, rhs) {
+            $crate::__approx_assert_fail!(
+                concat!("approx_ne!(", stringify!($lhs), ", ", stringify!($rhs), ")"),
+                lhs, rhs);
+        }
+    }};
+    ($lhs:expr, $rhs:expr, $($rest:tt)+) => {{
+        let (lhs, rhs) = (&$lhs, &$rhs);
+        if $crate::__approx_margin!($crate::Margin::default(), $($rest)+).eq(lhs, rhs) {
+            $crate::__approx_assert_fail!(
+                concat!("approx_ne!(", stringify!($lhs), ", ", stringify!($rhs),
+                        ", ", stringify!($($rest)+), ")"),
+                lhs, rhs);
+        }
+    }};
+}